@@ -2,14 +2,24 @@
 
 #[ink::contract]
 mod erc20 {
+    use ink::prelude::string::String;
     use ink::storage::Mapping;
 
     #[ink(storage)]
-    #[derive(Default)]
     pub struct Erc20 {
         total_supply: Balance,
         balances: Mapping<AccountId, Balance>,
         allowances: Mapping<(AccountId, AccountId), Balance>,
+        owner: AccountId,
+        /// Compressed secp256k1 public key of the trusted off-chain bridge
+        /// that signs mint receipts for `claim`.
+        bridge_pubkey: [u8; 33],
+        /// Receipt digests that have already been redeemed via `claim`,
+        /// keyed by the digest itself so each receipt can only mint once.
+        used_receipts: Mapping<[u8; 32], ()>,
+        name: String,
+        symbol: String,
+        decimals: u8,
     }
 
     #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
@@ -17,6 +27,10 @@ mod erc20 {
     pub enum Error {
         BalanceTooLow,
         AllowanceTooLow,
+        Overflow,
+        NotOwner,
+        InvalidSignature,
+        ReceiptAlreadyUsed,
     }
 
     type Result<T> = core::result::Result<T, Error>;
@@ -44,7 +58,13 @@ mod erc20 {
 
     impl Erc20 {
         #[ink(constructor)]
-        pub fn new(total_supply: Balance) -> Self {
+        pub fn new(
+            total_supply: Balance,
+            bridge_pubkey: [u8; 33],
+            name: String,
+            symbol: String,
+            decimals: u8,
+        ) -> Self {
             let mut balances = Mapping::new();
             balances.insert(Self::env().caller(), &total_supply);
             Self::env().emit_event(Transfer {
@@ -56,6 +76,12 @@ mod erc20 {
                 total_supply,
                 balances,
                 allowances: Default::default(),
+                owner: Self::env().caller(),
+                bridge_pubkey,
+                used_receipts: Default::default(),
+                name,
+                symbol,
+                decimals,
             }
         }
 
@@ -69,6 +95,21 @@ mod erc20 {
             self.balances.get(&who).unwrap_or_default()
         }
 
+        #[ink(message)]
+        pub fn token_name(&self) -> String {
+            self.name.clone()
+        }
+
+        #[ink(message)]
+        pub fn token_symbol(&self) -> String {
+            self.symbol.clone()
+        }
+
+        #[ink(message)]
+        pub fn token_decimals(&self) -> u8 {
+            self.decimals
+        }
+
         fn transfer_from_to(
             &mut self,
             from: AccountId,
@@ -76,14 +117,24 @@ mod erc20 {
             value: Balance,
         ) -> Result<()> {
             let balance_from = self.balance_of(from);
-            let balance_to = self.balance_of(to);
 
-            if value > balance_from {
-                return Err(Error::BalanceTooLow);
-            }
+            let new_balance_from = balance_from
+                .checked_sub(value)
+                .ok_or(Error::BalanceTooLow)?;
+
+            // Use the already-debited `from` balance when `from == to` so a
+            // self-transfer doesn't credit `value` on top of a stale balance.
+            // Compute both results before writing anything, so an `Err` here
+            // never leaves a half-applied transfer in storage.
+            let balance_to = if from == to {
+                new_balance_from
+            } else {
+                self.balance_of(to)
+            };
+            let new_balance_to = balance_to.checked_add(value).ok_or(Error::Overflow)?;
 
-            self.balances.insert(from, &(balance_from - value));
-            self.balances.insert(to, &(balance_to + value));
+            self.balances.insert(from, &new_balance_from);
+            self.balances.insert(to, &new_balance_to);
 
             self.env().emit_event(Transfer {
                 from: Some(from),
@@ -110,13 +161,12 @@ mod erc20 {
                 .allowances
                 .get((from, self.env().caller()))
                 .unwrap_or_default();
-            if value > allowance {
-                return Err(Error::AllowanceTooLow);
-            }
+            let new_allowance = allowance.checked_sub(value).ok_or(Error::AllowanceTooLow)?;
 
+            self.transfer_from_to(from, to, value)?;
             self.allowances
-                .insert((from, self.env().caller()), &(allowance - value));
-            self.transfer_from_to(from, to, value)
+                .insert((from, self.env().caller()), &new_allowance);
+            Ok(())
         }
 
         // approve
@@ -131,6 +181,142 @@ mod erc20 {
             });
             Ok(())
         }
+
+        /// Increases the caller's allowance for `spender` by `delta`, avoiding
+        /// the approve-overwrite race that a plain `approve` call is subject to.
+        #[ink(message)]
+        pub fn increase_allowance(&mut self, spender: AccountId, delta: Balance) -> Result<()> {
+            let owner = self.env().caller();
+            let allowance = self.allowances.get((owner, spender)).unwrap_or_default();
+            let new_allowance = allowance.checked_add(delta).ok_or(Error::Overflow)?;
+
+            self.allowances.insert((owner, spender), &new_allowance);
+            self.env().emit_event(Approval {
+                owner,
+                spender,
+                value: new_allowance,
+            });
+            Ok(())
+        }
+
+        /// Decreases the caller's allowance for `spender` by `delta`, failing
+        /// with `Error::AllowanceTooLow` rather than saturating to zero.
+        #[ink(message)]
+        pub fn decrease_allowance(&mut self, spender: AccountId, delta: Balance) -> Result<()> {
+            let owner = self.env().caller();
+            let allowance = self.allowances.get((owner, spender)).unwrap_or_default();
+            let new_allowance = allowance.checked_sub(delta).ok_or(Error::AllowanceTooLow)?;
+
+            self.allowances.insert((owner, spender), &new_allowance);
+            self.env().emit_event(Approval {
+                owner,
+                spender,
+                value: new_allowance,
+            });
+            Ok(())
+        }
+
+        /// Mints `value` new tokens to `to`, increasing both the recipient's
+        /// balance and `total_supply`. Only callable by the contract owner.
+        #[ink(message)]
+        pub fn mint(&mut self, to: AccountId, value: Balance) -> Result<()> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+
+            let balance_to = self.balance_of(to);
+            let new_balance_to = balance_to.checked_add(value).ok_or(Error::Overflow)?;
+            let new_total_supply = self
+                .total_supply
+                .checked_add(value)
+                .ok_or(Error::Overflow)?;
+
+            self.balances.insert(to, &new_balance_to);
+            self.total_supply = new_total_supply;
+
+            self.env().emit_event(Transfer {
+                from: None,
+                to: Some(to),
+                value,
+            });
+            Ok(())
+        }
+
+        /// Burns `value` tokens from `from`, decreasing both the holder's
+        /// balance and `total_supply`. Only callable by the contract owner.
+        #[ink(message)]
+        pub fn burn(&mut self, from: AccountId, value: Balance) -> Result<()> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+
+            let balance_from = self.balance_of(from);
+            let new_balance_from = balance_from
+                .checked_sub(value)
+                .ok_or(Error::BalanceTooLow)?;
+            let new_total_supply = self
+                .total_supply
+                .checked_sub(value)
+                .ok_or(Error::Overflow)?;
+
+            self.balances.insert(from, &new_balance_from);
+            self.total_supply = new_total_supply;
+
+            self.env().emit_event(Transfer {
+                from: Some(from),
+                to: None,
+                value,
+            });
+            Ok(())
+        }
+
+        /// Redeems a bridge-signed receipt authorizing a mint of `value` to
+        /// `to`. The receipt digest is `blake2b(scale::encode((to, value,
+        /// nonce)))`; `signature` must be the bridge's ECDSA signature over
+        /// that digest. Each digest can only be redeemed once, which binds
+        /// the receipt to its `nonce` and prevents replay.
+        #[ink(message)]
+        pub fn claim(
+            &mut self,
+            to: AccountId,
+            value: Balance,
+            nonce: u64,
+            signature: [u8; 65],
+        ) -> Result<()> {
+            let encoded = scale::Encode::encode(&(to, value, nonce));
+            let mut digest = [0u8; 32];
+            ink::env::hash_bytes::<ink::env::hash::Blake2x256>(&encoded, &mut digest);
+
+            let mut recovered_pubkey = [0u8; 33];
+            self.env()
+                .ecdsa_recover(&signature, &digest, &mut recovered_pubkey)
+                .map_err(|_| Error::InvalidSignature)?;
+            if recovered_pubkey != self.bridge_pubkey {
+                return Err(Error::InvalidSignature);
+            }
+
+            if self.used_receipts.contains(digest) {
+                return Err(Error::ReceiptAlreadyUsed);
+            }
+
+            let balance_to = self.balance_of(to);
+            let new_balance_to = balance_to.checked_add(value).ok_or(Error::Overflow)?;
+            let new_total_supply = self
+                .total_supply
+                .checked_add(value)
+                .ok_or(Error::Overflow)?;
+
+            self.used_receipts.insert(digest, &());
+            self.balances.insert(to, &new_balance_to);
+            self.total_supply = new_total_supply;
+
+            self.env().emit_event(Transfer {
+                from: None,
+                to: Some(to),
+                value,
+            });
+            Ok(())
+        }
     }
 
     type Event = <Erc20 as ::ink::reflect::ContractEventBase>::Type;
@@ -140,7 +326,13 @@ mod erc20 {
 
         #[ink::test]
         fn constructor_works() {
-            let erc20 = Erc20::new(123);
+            let erc20 = Erc20::new(
+                123,
+                [0u8; 33],
+                String::from("Erc20 Token"),
+                String::from("ERC20"),
+                18,
+            );
             let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
             assert_eq!(erc20.total_supply, 123);
             assert_eq!(erc20.balance_of(accounts.alice), 123);
@@ -160,10 +352,31 @@ mod erc20 {
             }
         }
 
+        // test metadata getters
+        #[ink::test]
+        fn metadata_works() {
+            let erc20 = Erc20::new(
+                123,
+                [0u8; 33],
+                String::from("Erc20 Token"),
+                String::from("ERC20"),
+                18,
+            );
+            assert_eq!(erc20.token_name(), String::from("Erc20 Token"));
+            assert_eq!(erc20.token_symbol(), String::from("ERC20"));
+            assert_eq!(erc20.token_decimals(), 18);
+        }
+
         // test transfer
         #[ink::test]
         fn transfer_works() {
-            let mut erc20 = Erc20::new(1000);
+            let mut erc20 = Erc20::new(
+                1000,
+                [0u8; 33],
+                String::from("Erc20 Token"),
+                String::from("ERC20"),
+                18,
+            );
             let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
             assert_eq!(erc20.balance_of(accounts.alice), 1000);
             assert_eq!(erc20.balance_of(accounts.bob), 0);
@@ -176,7 +389,13 @@ mod erc20 {
         // test transfer with low balance
         #[ink::test]
         fn transfer_with_low_balance_show_fail() {
-            let mut erc20 = Erc20::new(1000);
+            let mut erc20 = Erc20::new(
+                1000,
+                [0u8; 33],
+                String::from("Erc20 Token"),
+                String::from("ERC20"),
+                18,
+            );
             let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
             assert_eq!(erc20.balance_of(accounts.alice), 1000);
             assert_eq!(erc20.balance_of(accounts.bob), 0);
@@ -188,6 +407,179 @@ mod erc20 {
             assert_eq!(erc20.balance_of(accounts.alice), 1000);
             assert_eq!(erc20.balance_of(accounts.bob), 0);
         }
+
+        // test transfer into a near-max balance overflows gracefully
+        #[ink::test]
+        fn transfer_with_overflowing_balance_fails() {
+            let mut erc20 = Erc20::new(
+                1000,
+                [0u8; 33],
+                String::from("Erc20 Token"),
+                String::from("ERC20"),
+                18,
+            );
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            erc20.balances.insert(accounts.bob, &Balance::MAX);
+
+            assert_eq!(erc20.transfer(accounts.bob, 1), Err(Error::Overflow));
+            assert_eq!(erc20.balance_of(accounts.alice), 1000);
+            assert_eq!(erc20.balance_of(accounts.bob), Balance::MAX);
+        }
+
+        // test transferring to yourself does not mint extra balance
+        #[ink::test]
+        fn transfer_to_self_does_not_inflate_balance() {
+            let mut erc20 = Erc20::new(
+                1000,
+                [0u8; 33],
+                String::from("Erc20 Token"),
+                String::from("ERC20"),
+                18,
+            );
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert_eq!(erc20.transfer(accounts.alice, 100), Ok(()));
+            assert_eq!(erc20.balance_of(accounts.alice), 1000);
+        }
+
+        // test increase_allowance
+        #[ink::test]
+        fn increase_allowance_works() {
+            let mut erc20 = Erc20::new(
+                1000,
+                [0u8; 33],
+                String::from("Erc20 Token"),
+                String::from("ERC20"),
+                18,
+            );
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert_eq!(erc20.approve(accounts.bob, 100), Ok(()));
+            assert_eq!(erc20.increase_allowance(accounts.bob, 50), Ok(()));
+            assert_eq!(
+                erc20.allowances.get((accounts.alice, accounts.bob)),
+                Some(150)
+            );
+        }
+
+        // test decrease_allowance
+        #[ink::test]
+        fn decrease_allowance_works() {
+            let mut erc20 = Erc20::new(
+                1000,
+                [0u8; 33],
+                String::from("Erc20 Token"),
+                String::from("ERC20"),
+                18,
+            );
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert_eq!(erc20.approve(accounts.bob, 100), Ok(()));
+            assert_eq!(erc20.decrease_allowance(accounts.bob, 40), Ok(()));
+            assert_eq!(
+                erc20.allowances.get((accounts.alice, accounts.bob)),
+                Some(60)
+            );
+        }
+
+        // test decrease_allowance below zero fails rather than saturating
+        #[ink::test]
+        fn decrease_allowance_below_zero_fails() {
+            let mut erc20 = Erc20::new(
+                1000,
+                [0u8; 33],
+                String::from("Erc20 Token"),
+                String::from("ERC20"),
+                18,
+            );
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert_eq!(erc20.approve(accounts.bob, 100), Ok(()));
+            assert_eq!(
+                erc20.decrease_allowance(accounts.bob, 101),
+                Err(Error::AllowanceTooLow)
+            );
+            assert_eq!(
+                erc20.allowances.get((accounts.alice, accounts.bob)),
+                Some(100)
+            );
+        }
+
+        // test mint
+        #[ink::test]
+        fn mint_works() {
+            let mut erc20 = Erc20::new(
+                1000,
+                [0u8; 33],
+                String::from("Erc20 Token"),
+                String::from("ERC20"),
+                18,
+            );
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert_eq!(erc20.mint(accounts.bob, 100), Ok(()));
+            assert_eq!(erc20.balance_of(accounts.bob), 100);
+            assert_eq!(erc20.total_supply(), 1100);
+        }
+
+        // test mint by non-owner
+        #[ink::test]
+        fn mint_by_non_owner_fails() {
+            let mut erc20 = Erc20::new(
+                1000,
+                [0u8; 33],
+                String::from("Erc20 Token"),
+                String::from("ERC20"),
+                18,
+            );
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(erc20.mint(accounts.bob, 100), Err(Error::NotOwner));
+        }
+
+        // test burn
+        #[ink::test]
+        fn burn_works() {
+            let mut erc20 = Erc20::new(
+                1000,
+                [0u8; 33],
+                String::from("Erc20 Token"),
+                String::from("ERC20"),
+                18,
+            );
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert_eq!(erc20.burn(accounts.alice, 100), Ok(()));
+            assert_eq!(erc20.balance_of(accounts.alice), 900);
+            assert_eq!(erc20.total_supply(), 900);
+        }
+
+        // test burn with low balance
+        #[ink::test]
+        fn burn_with_low_balance_fails() {
+            let mut erc20 = Erc20::new(
+                1000,
+                [0u8; 33],
+                String::from("Erc20 Token"),
+                String::from("ERC20"),
+                18,
+            );
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert_eq!(erc20.burn(accounts.alice, 1001), Err(Error::BalanceTooLow));
+            assert_eq!(erc20.balance_of(accounts.alice), 1000);
+        }
+
+        // test claim with a bogus signature
+        #[ink::test]
+        fn claim_with_invalid_signature_fails() {
+            let mut erc20 = Erc20::new(
+                1000,
+                [0u8; 33],
+                String::from("Erc20 Token"),
+                String::from("ERC20"),
+                18,
+            );
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert_eq!(
+                erc20.claim(accounts.bob, 100, 0, [0u8; 65]),
+                Err(Error::InvalidSignature)
+            );
+            assert_eq!(erc20.balance_of(accounts.bob), 0);
+        }
     }
 
     #[cfg(all(test, feature = "e2e-tests"))]
@@ -200,7 +592,13 @@ mod erc20 {
         #[ink_e2e::test]
         async fn e2e_transfer(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
             // Given
-            let constructor = Erc20Ref::new(1000);
+            let constructor = Erc20Ref::new(
+                1000,
+                [0u8; 33],
+                String::from("Erc20 Token"),
+                String::from("ERC20"),
+                18,
+            );
             let contract_account_id = client
                 .instantiate("erc20", &ink_e2e::alice(), constructor, 0, None)
                 .await